@@ -9,8 +9,9 @@
 
 //! The dirichlet distribution.
 
+use num_traits::Float;
 use rand::Rng;
-use crate::Distribution;
+use crate::{Distribution, Exp1, Open01, StandardNormal};
 use crate::gamma::Gamma;
 
 /// The dirichelet distribution `Dirichlet(alpha)`.
@@ -19,6 +20,10 @@ use crate::gamma::Gamma;
 /// probability distributions parameterized by a vector alpha of positive reals.
 /// It is a multivariate generalization of the beta distribution.
 ///
+/// The `F` type parameter is the type used to represent the concentration
+/// parameters and the samples drawn from the distribution; it carries the
+/// same bounds as [`Gamma`], which this distribution is built on.
+///
 /// # Example
 ///
 /// ```
@@ -26,13 +31,19 @@ use crate::gamma::Gamma;
 /// use rand_distr::Dirichlet;
 ///
 /// let dirichlet = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
-/// let samples = dirichlet.sample(&mut rand::thread_rng());
+/// let samples: Vec<f64> = dirichlet.sample(&mut rand::thread_rng());
 /// println!("{:?} is from a Dirichlet([1.0, 2.0, 3.0]) distribution", samples);
 /// ```
 #[derive(Clone, Debug)]
-pub struct Dirichlet {
+pub struct Dirichlet<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
     /// Concentration parameters (alpha)
-    alpha: Vec<f64>,
+    alpha: Vec<F>,
 }
 
 /// Error type returned from `Dirchlet::new`.
@@ -46,18 +57,24 @@ pub enum Error {
     SizeTooSmall,
 }
 
-impl Dirichlet {
+impl<F> Dirichlet<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
     /// Construct a new `Dirichlet` with the given alpha parameter `alpha`.
     ///
     /// Requires `alpha.len() >= 2`.
     #[inline]
-    pub fn new<V: Into<Vec<f64>>>(alpha: V) -> Result<Dirichlet, Error> {
+    pub fn new<V: Into<Vec<F>>>(alpha: V) -> Result<Dirichlet<F>, Error> {
         let a = alpha.into();
         if a.len() < 2 {
             return Err(Error::AlphaTooShort);
         }
         for i in 0..a.len() {
-            if !(a[i] > 0.0) {
+            if !(a[i] > F::zero()) {
                 return Err(Error::AlphaTooSmall);
             }
         }
@@ -69,8 +86,8 @@ impl Dirichlet {
     ///
     /// Requires `size >= 2`.
     #[inline]
-    pub fn new_with_size(alpha: f64, size: usize) -> Result<Dirichlet, Error> {
-        if !(alpha > 0.0) {
+    pub fn new_with_size(alpha: F, size: usize) -> Result<Dirichlet<F>, Error> {
+        if !(alpha > F::zero()) {
             return Err(Error::AlphaTooSmall);
         }
         if size < 2 {
@@ -82,20 +99,26 @@ impl Dirichlet {
     }
 }
 
-impl Distribution<Vec<f64>> for Dirichlet {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+impl<F> Distribution<Vec<F>> for Dirichlet<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<F> {
         let n = self.alpha.len();
-        let mut samples = vec![0.0f64; n];
-        let mut sum = 0.0f64;
+        let mut samples = vec![F::zero(); n];
+        let mut sum = F::zero();
 
         for i in 0..n {
-            let g = Gamma::new(self.alpha[i], 1.0).unwrap();
+            let g = Gamma::new(self.alpha[i], F::one()).unwrap();
             samples[i] = g.sample(rng);
-            sum += samples[i];
+            sum = sum + samples[i];
         }
-        let invacc = 1.0 / sum;
+        let invacc = F::one() / sum;
         for i in 0..n {
-            samples[i] *= invacc;
+            samples[i] = samples[i] * invacc;
         }
         samples
     }
@@ -108,7 +131,7 @@ mod test {
 
     #[test]
     fn test_dirichlet() {
-        let d = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
+        let d: Dirichlet<f64> = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
         let mut rng = crate::test::rng(221);
         let samples = d.sample(&mut rng);
         let _: Vec<f64> = samples
@@ -124,7 +147,7 @@ mod test {
     fn test_dirichlet_with_param() {
         let alpha = 0.5f64;
         let size = 2;
-        let d = Dirichlet::new_with_size(alpha, size).unwrap();
+        let d: Dirichlet<f64> = Dirichlet::new_with_size(alpha, size).unwrap();
         let mut rng = crate::test::rng(221);
         let samples = d.sample(&mut rng);
         let _: Vec<f64> = samples
@@ -136,6 +159,20 @@ mod test {
             .collect();
     }
 
+    #[test]
+    fn test_dirichlet_f32() {
+        let d: Dirichlet<f32> = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
+        let mut rng = crate::test::rng(221);
+        let samples = d.sample(&mut rng);
+        let _: Vec<f32> = samples
+            .into_iter()
+            .map(|x| {
+                assert!(x > 0.0);
+                x
+            })
+            .collect();
+    }
+
     #[test]
     #[should_panic]
     fn test_dirichlet_invalid_length() {